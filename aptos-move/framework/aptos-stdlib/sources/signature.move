@@ -49,6 +49,22 @@ module aptos_std::signature {
         message: vector<u8>
     ): bool;
 
+    /// Return true if, for every index `i`, the Ed25519 signature `signatures[i]` on `messages[i]`
+    /// verifies against the Ed25519 public key `public_keys[i]`. The arguments follow the order of
+    /// `ed25519_verify` (signatures, public keys, messages), with one entry per signature.
+    /// Signatures are batch-verified, but the result always agrees with calling `ed25519_verify`
+    /// on each entry: in particular, small subgroup public keys and R-components are rejected.
+    /// Charges a base cost, a discounted per-signature cost and the per-byte message cost of
+    /// `ed25519_verify`.
+    /// Returns `true` on an empty batch.
+    /// Returns `false` if any signature in the batch would make `ed25519_verify` return `false`.
+    /// Aborts if `signatures`, `public_keys` and `messages` do not all have the same length.
+    native public fun ed25519_verify_batch(
+        signatures: vector<vector<u8>>,
+        public_keys: vector<vector<u8>>,
+        messages: vector<vector<u8>>
+    ): bool;
+
     /// Recovers the signer's public key from a secp256k1 `signature` provided the `recovery_id` and signed
     /// `message` (32 byte digest).
     /// Returns `(public_key, true)` if inputs are valid and `([], false)` if invalid.
@@ -89,6 +105,81 @@ module aptos_std::signature {
         assert!(ok == false, 5);
     }
 
+    #[test_only]
+    fun ed25519_test_batch(): (vector<vector<u8>>, vector<vector<u8>>, vector<vector<u8>>) {
+        // SK: 0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef
+        // SK: fedcba9876543210fedcba9876543210fedcba9876543210fedcba9876543210
+        let signatures = vector[
+            x"0170231c56ea97f8ed3efcfc7e432928946a914d34e15b71ba59493bc5d98fc0dbf01d1c60eb121177e591ff6b465525c196495b003d9d025c1b4f75473c4307",
+            x"0bfc5d944925f7337dcc2c49ddf05c5d0fc0c3f84e9f06785917f87000dc70d50337b7574278c949b2ff7f0272c1b0978e862ff1655b52d908507a1bf70d3708",
+        ];
+        let public_keys = vector[
+            x"207a067892821e25d770f1fba0c47c11ff4b813e54162ece9eb839e076231ab6",
+            x"568fa5531c7d74d27140d1ae964ff3c0b19f556fc23a879b251495fc1ba78631",
+        ];
+        let messages = vector[
+            b"Hello Aptos!",
+            b"Hello again, Aptos!",
+        ];
+        (signatures, public_keys, messages)
+    }
+
+    #[test]
+    fun test_ed25519_verify_batch() {
+        use std::vector;
+
+        let (signatures, public_keys, messages) = ed25519_test_batch();
+        assert!(ed25519_verify_batch(signatures, public_keys, messages), 1);
+        assert!(ed25519_verify_batch(vector[], vector[], vector[]), 2);
+
+        // The second signature does not sign the tampered message
+        let (signatures, public_keys, messages) = ed25519_test_batch();
+        *vector::borrow_mut(&mut messages, 1) = b"Hello again, Aptos?";
+        assert!(!ed25519_verify_batch(signatures, public_keys, messages), 3);
+
+        // Flipped bits in the R-component of the first signature, followed by a valid entry
+        let (signatures, public_keys, messages) = ed25519_test_batch();
+        *vector::borrow_mut(&mut signatures, 0) =
+            x"0070231c56ea97f8ed3efcfc7e432928946a914d34e15b71ba59493bc5d98fc0dbf01d1c60eb121177e591ff6b465525c196495b003d9d025c1b4f75473c4307";
+        assert!(!ed25519_verify_batch(signatures, public_keys, messages), 4);
+
+        // A signature that is not 64 bytes long
+        let (signatures, public_keys, messages) = ed25519_test_batch();
+        *vector::borrow_mut(&mut signatures, 0) = x"0170231c56ea97f8";
+        assert!(!ed25519_verify_batch(signatures, public_keys, messages), 5);
+
+        // A public key that is not 32 bytes long
+        let (signatures, public_keys, messages) = ed25519_test_batch();
+        *vector::borrow_mut(&mut public_keys, 0) = x"207a067892821e25";
+        assert!(!ed25519_verify_batch(signatures, public_keys, messages), 6);
+    }
+
+    #[test]
+    fun test_ed25519_verify_batch_small_order() {
+        use std::vector;
+
+        // With the identity as both public key and R-component, and s = 0, the cofactorless
+        // equation R = sB - hA holds for every message, so a plain batch equation would accept it.
+        let identity = x"0100000000000000000000000000000000000000000000000000000000000000";
+        let small_order_signature = copy identity;
+        vector::append(&mut small_order_signature, x"0000000000000000000000000000000000000000000000000000000000000000");
+
+        assert!(!ed25519_verify(copy small_order_signature, copy identity, b"Hello Aptos!"), 1);
+
+        let (signatures, public_keys, messages) = ed25519_test_batch();
+        *vector::borrow_mut(&mut signatures, 0) = small_order_signature;
+        *vector::borrow_mut(&mut public_keys, 0) = identity;
+        assert!(!ed25519_verify_batch(signatures, public_keys, messages), 2);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 3)]
+    fun test_ed25519_verify_batch_length_mismatch() {
+        let (signatures, public_keys, messages) = ed25519_test_batch();
+        std::vector::pop_back(&mut messages);
+        ed25519_verify_batch(signatures, public_keys, messages);
+    }
+
     #[test]
     /// Tests verification of a random BLS signature created using sk = x""
     fun test_bls12381() {
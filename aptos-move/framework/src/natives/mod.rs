@@ -24,6 +24,8 @@ pub mod status {
     pub const NFE_EXPECTED_STRUCT_TYPE_TAG: u64 = 0x1;
     // Failure in address parsing (likely no correct length)
     pub const NFE_UNABLE_TO_PARSE_ADDRESS: u64 = 0x2;
+    // Batch signature verification received inputs of differing lengths
+    pub const NFE_BATCH_VERIFY_LENGTH_MISMATCH: u64 = 0x3;
 }
 
 pub fn all_natives(framework_addr: AccountAddress) -> NativeFunctionTable {
@@ -50,6 +52,11 @@ pub fn all_natives(framework_addr: AccountAddress) -> NativeFunctionTable {
             "ed25519_verify",
             signature::native_ed25519_signature_verification,
         ),
+        (
+            "signature",
+            "ed25519_verify_batch",
+            signature::native_ed25519_batch_verify,
+        ),
         (
             "signature",
             "secp256k1_recover",
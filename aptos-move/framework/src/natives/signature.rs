@@ -5,7 +5,7 @@ use aptos_crypto::{bls12381, ed25519, traits::*};
 use curve25519_dalek::edwards::CompressedEdwardsY;
 use move_deps::{
     move_binary_format::errors::PartialVMResult,
-    move_core_types::gas_schedule::GasCost,
+    move_core_types::gas_schedule::{GasAlgebra, GasCost, InternalGasUnits},
     move_vm_runtime::native_functions::NativeContext,
     move_vm_types::{
         gas_schedule::NativeCostIndex,
@@ -85,6 +85,21 @@ pub fn native_ed25519_publickey_validation(
     Ok(NativeResult::ok(cost, smallvec![Value::bool(valid)]))
 }
 
+/// Each signature in `ed25519_verify_batch` is charged `1 / ED25519_BATCH_VERIFY_DISCOUNT` of an
+/// `ED25519_VERIFY` unit, on top of the per-byte cost of hashing its message, to reflect that
+/// batch verification does less curve arithmetic per signature than `ed25519_verify`.
+const ED25519_BATCH_VERIFY_DISCOUNT: u64 = 2;
+
+/// Parses an Ed25519 signature and public key, returning `None` if either is malformed.
+fn ed25519_parse(
+    signature: &[u8],
+    pubkey: &[u8],
+) -> Option<(ed25519::Ed25519Signature, ed25519::Ed25519PublicKey)> {
+    let sig = ed25519::Ed25519Signature::try_from(signature).ok()?;
+    let pk = ed25519::Ed25519PublicKey::try_from(pubkey).ok()?;
+    Some((sig, pk))
+}
+
 pub fn native_ed25519_signature_verification(
     context: &mut NativeContext,
     _ty_args: Vec<Type>,
@@ -103,15 +118,9 @@ pub fn native_ed25519_signature_verification(
         msg.len(),
     );
 
-    let sig = match ed25519::Ed25519Signature::try_from(signature.as_slice()) {
-        Ok(sig) => sig,
-        Err(_) => {
-            return Ok(NativeResult::ok(cost, smallvec![Value::bool(false)]));
-        }
-    };
-    let pk = match ed25519::Ed25519PublicKey::try_from(pubkey.as_slice()) {
-        Ok(pk) => pk,
-        Err(_) => {
+    let (sig, pk) = match ed25519_parse(&signature, &pubkey) {
+        Some(parsed) => parsed,
+        None => {
             return Ok(NativeResult::ok(cost, smallvec![Value::bool(false)]));
         }
     };
@@ -123,6 +132,69 @@ pub fn native_ed25519_signature_verification(
     ))
 }
 
+pub fn native_ed25519_batch_verify(
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 3);
+
+    let msgs = pop_arg!(arguments, Vec<Value>)
+        .into_iter()
+        .map(|msg| msg.value_as::<Vec<u8>>())
+        .collect::<PartialVMResult<Vec<_>>>()?;
+    let pubkeys = pop_arg!(arguments, Vec<Value>);
+    let signatures = pop_arg!(arguments, Vec<Value>);
+
+    // A base cost of one `ED25519_VERIFY` unit, a discounted cost per entry of the longest input,
+    // and the same per-byte cost as `ed25519_verify` for every message.
+    let num_entries = signatures.len().max(pubkeys.len()).max(msgs.len()) as u64;
+    let num_msg_bytes = msgs
+        .iter()
+        .fold(0u64, |total, msg| total.saturating_add(msg.len() as u64));
+    let unit_cost = native_gas(context.cost_table(), NativeCostIndex::ED25519_VERIFY, 1);
+    let cost = unit_cost
+        .add(InternalGasUnits::new(
+            unit_cost.get().saturating_mul(num_entries) / ED25519_BATCH_VERIFY_DISCOUNT,
+        ))
+        .add(InternalGasUnits::new(
+            unit_cost.get().saturating_mul(num_msg_bytes),
+        ));
+
+    if pubkeys.len() != signatures.len() || msgs.len() != signatures.len() {
+        return Ok(NativeResult::err(
+            cost,
+            super::status::NFE_BATCH_VERIFY_LENGTH_MISMATCH,
+        ));
+    }
+
+    let mut sigs = Vec::with_capacity(signatures.len());
+    let mut pks = Vec::with_capacity(pubkeys.len());
+    for (signature, pubkey) in signatures.into_iter().zip(pubkeys) {
+        let signature = signature.value_as::<Vec<u8>>()?;
+        let pubkey = pubkey.value_as::<Vec<u8>>()?;
+
+        match ed25519_parse(&signature, &pubkey) {
+            Some((sig, pk)) => {
+                sigs.push(sig);
+                pks.push(pk);
+            }
+            None => {
+                return Ok(NativeResult::ok(cost, smallvec![Value::bool(false)]));
+            }
+        }
+    }
+
+    let msgs: Vec<&[u8]> = msgs.iter().map(|msg| msg.as_slice()).collect();
+    let verify_result =
+        ed25519::Ed25519Signature::batch_verify_arbitrary_msgs(&msgs, &pks, &sigs).is_ok();
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::bool(verify_result)],
+    ))
+}
+
 pub fn native_secp256k1_recover(
     _context: &mut NativeContext,
     _ty_args: Vec<Type>,
@@ -35,9 +35,10 @@ use crate::{
     hash::{CryptoHash, CryptoHasher},
     traits::*,
 };
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, ensure, Result};
 use aptos_crypto_derive::{DeserializeKey, SerializeKey, SilentDebug, SilentDisplay};
 use core::convert::TryFrom;
+use curve25519_dalek::edwards::CompressedEdwardsY;
 use serde::Serialize;
 use std::{cmp::Ordering, fmt};
 
@@ -218,6 +219,66 @@ impl Ed25519Signature {
         Ok(())
     }
 
+    /// Checks that `signatures[i]` is valid for the arbitrary `messages[i]` using `public_keys[i]`
+    /// for every `i`, succeeding exactly when each signature would pass
+    /// [`Signature::verify_arbitrary_msg`]. Outside of this crate, this particular function should
+    /// only be used for native signature verification in Move.
+    ///
+    /// Like `verify_arbitrary_msg`, this rejects mauled signatures, as well as any signature whose
+    /// R-component or public key lies in a small subgroup. Entries whose R-component and public key
+    /// are both in the prime-order subgroup are then checked together with ed25519-dalek's
+    /// deterministic batch verification: for such points the cofactorless batch equation holds if
+    /// and only if every individual equation holds (up to a negligible probability over the
+    /// transcript-derived coefficients). The remaining, mixed-order, entries are verified one by
+    /// one with `verify_arbitrary_msg`, since a batch equation could cancel out their torsion
+    /// components where strict verification would not.
+    pub fn batch_verify_arbitrary_msgs(
+        messages: &[&[u8]],
+        public_keys: &[Ed25519PublicKey],
+        signatures: &[Ed25519Signature],
+    ) -> Result<()> {
+        ensure!(
+            messages.len() == public_keys.len() && messages.len() == signatures.len(),
+            "Batch has {} messages, {} public keys and {} signatures",
+            messages.len(),
+            public_keys.len(),
+            signatures.len()
+        );
+
+        let mut batch_messages = Vec::with_capacity(messages.len());
+        let mut batch_public_keys = Vec::with_capacity(messages.len());
+        let mut batch_signatures = Vec::with_capacity(messages.len());
+        for ((message, public_key), signature) in messages.iter().zip(public_keys).zip(signatures) {
+            let signature_bytes = signature.to_bytes();
+            Ed25519Signature::check_s_malleability(&signature_bytes)?;
+
+            let r_point = CompressedEdwardsY::from_slice(&signature_bytes[..32])
+                .decompress()
+                .ok_or_else(|| anyhow!("Signature R-component is not a valid curve point"))?;
+            let public_key_point = CompressedEdwardsY(public_key.to_bytes())
+                .decompress()
+                .ok_or_else(|| anyhow!("Public key is not a valid curve point"))?;
+            ensure!(
+                !r_point.is_small_order() && !public_key_point.is_small_order(),
+                "Signature R-component or public key is in a small subgroup"
+            );
+
+            if r_point.is_torsion_free() && public_key_point.is_torsion_free() {
+                batch_messages.push(*message);
+                batch_public_keys.push(public_key.0);
+                batch_signatures.push(signature.0);
+            } else {
+                signature.verify_arbitrary_msg(message, public_key)?;
+            }
+        }
+
+        if batch_signatures.is_empty() {
+            return Ok(());
+        }
+        ed25519_dalek::verify_batch(&batch_messages, &batch_signatures, &batch_public_keys)
+            .map_err(|e| anyhow!("{}", e))
+    }
+
     /// Check if S < L to capture invalid signatures.
     fn check_s_lt_l(s: &[u8]) -> bool {
         for i in (0..32).rev() {
@@ -324,6 +324,23 @@ proptest! {
         prop_assert!(Ed25519Signature::batch_verify(&message, signatures).is_err());
     }
 
+    #[test]
+    fn test_batch_verify_arbitrary_msgs(
+        messages in vec(any::<Vec<u8>>(), 10),
+        keypairs in proptest::array::uniform10(uniform_keypair_strategy::<Ed25519PrivateKey, Ed25519PublicKey>())
+    ) {
+        let public_keys: Vec<Ed25519PublicKey> = keypairs.iter().map(|keypair| keypair.public_key.clone()).collect();
+        let mut signatures: Vec<Ed25519Signature> = keypairs.iter().zip(&messages).map(|(keypair, message)| {
+            keypair.private_key.sign_arbitrary_message(message)
+        }).collect();
+        let messages: Vec<&[u8]> = messages.iter().map(|message| message.as_slice()).collect();
+        prop_assert!(Ed25519Signature::batch_verify_arbitrary_msgs(&messages, &public_keys, &signatures).is_ok());
+        prop_assert!(Ed25519Signature::batch_verify_arbitrary_msgs(&messages[1..], &public_keys, &signatures).is_err());
+        // We swap the first two signatures, resulting in two incorrect signatures
+        signatures.swap(0, 1);
+        prop_assert!(Ed25519Signature::batch_verify_arbitrary_msgs(&messages, &public_keys, &signatures).is_err());
+    }
+
     #[test]
     fn test_keys_custom_serialisation(
         keypair in uniform_keypair_strategy::<Ed25519PrivateKey, Ed25519PublicKey>()
@@ -496,6 +513,24 @@ proptest! {
         let sig = Ed25519Signature::from_bytes_unchecked(sig_bytes.as_ref()).unwrap();
         prop_assert!(pk.verify_struct_signature(&m, &sig).is_err());
     }
+
+    // Small subgroup signatures that pass the permissive check must also fail batch verification.
+    #[allow(non_snake_case)]
+    #[test]
+    fn test_batch_verify_arbitrary_msgs_smallorder((R, A, m) in small_order_pk_with_adversarial_message()) {
+        let pk = Ed25519PublicKey::from_bytes_unchecked(&A.compress().to_bytes()).unwrap();
+        let sig_bytes : Vec<u8> = [R.compress().to_bytes(), Scalar::zero().to_bytes()].concat();
+        let sig = Ed25519Signature::from_bytes_unchecked(sig_bytes.as_ref()).unwrap();
+        let msg = signing_message(&m);
+
+        // We expect ed25519-dalek verify to succeed, since R + hA = Identity with s = 0
+        let sig_dalek = ed25519_dalek::Signature::from_bytes(&sig_bytes).unwrap();
+        let pk_dalek = ed25519_dalek::PublicKey::from_bytes(&A.compress().to_bytes()).unwrap();
+        prop_assert!(pk_dalek.verify(msg.as_slice(), &sig_dalek).is_ok());
+
+        // ...but batch verification must be as strict as Ed25519Signature::verify_arbitrary_msg
+        prop_assert!(Ed25519Signature::batch_verify_arbitrary_msgs(&[msg.as_slice()], &[pk], &[sig]).is_err());
+    }
 }
 
 // The 8-torsion subgroup E[8].